@@ -0,0 +1,392 @@
+//! Async mirror of the blocking API, built on `embedded-hal-async`.
+//!
+//! Enabled with the `async` feature. [`Lm75`] is generic
+//! over any `embedded_hal_async::i2c::I2c` implementation and exposes an
+//! `async fn` equivalent of every method on the blocking [`crate::Lm75`].
+//! The register encoding/decoding is shared with the blocking driver via
+//! the [`crate::conversion`] module, so there is no duplicated math.
+
+use crate::conversion::{
+    convert_temp_from_register, convert_temp_to_register, resolution_config_bits, BitFlags, Register,
+};
+use crate::{ic, Address, Config, Error, FaultQueue, OsMode, OsPolarity, Resolution, SampleRate};
+use core::marker::PhantomData;
+use embedded_hal_async::i2c::I2c;
+
+/// Alternative name kept for API stability; an alias for [`Address`].
+pub type SlaveAddr = Address;
+
+/// Async LM75 device driver.
+///
+/// Mirrors [`crate::Lm75`] one-for-one, but built on
+/// `embedded_hal_async::i2c::I2c` for use on cooperatively-scheduled async
+/// executors (Embassy, ...).
+#[derive(Debug, Default)]
+pub struct Lm75<I2C, IC = ic::Lm75> {
+    i2c: I2C,
+    address: u8,
+    config: Config,
+    resolution: Resolution,
+    sample_rate: SampleRate,
+    extended_mode: bool,
+    _ic: PhantomData<IC>,
+}
+
+impl<I2C> Lm75<I2C, ic::Lm75> {
+    /// Create a new instance of the device for a plain LM75/LM75A-class part.
+    pub fn new(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::Ds7505> {
+    /// Create a new instance of the device for a DS7505.
+    pub fn new_ds7505(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::Ds1775> {
+    /// Create a new instance of the device for a DS1775.
+    pub fn new_ds1775(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::Tmp175> {
+    /// Create a new instance of the device for a TMP175/TMP275.
+    pub fn new_tmp175(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::G751> {
+    /// Create a new instance of the device for a G751.
+    pub fn new_g751(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::Pct2075> {
+    /// Create a new instance of the device for a PCT2075.
+    pub fn new_pct2075(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C> Lm75<I2C, ic::Tmp102> {
+    /// Create a new instance of the device for a TMP102/TMP112.
+    pub fn new_tmp102(i2c: I2C, address: impl Into<Address>) -> Self {
+        Self::new_variant(i2c, address)
+    }
+}
+
+impl<I2C, IC> Lm75<I2C, IC>
+where
+    IC: ic::Ic,
+{
+    /// Create a new instance of the device for any supported variant `IC`.
+    pub fn new_variant(i2c: I2C, address: impl Into<Address>) -> Self {
+        let () = IC::ASSERT_RESOLUTION_EXTENDED_MODE_DISJOINT;
+        Lm75 {
+            i2c,
+            address: address.into().0,
+            config: Config::default(),
+            resolution: IC::DEFAULT_RESOLUTION,
+            sample_rate: if IC::HAS_SAMPLE_RATE {
+                SampleRate::default()
+            } else {
+                SampleRate::none()
+            },
+            extended_mode: false,
+            _ic: PhantomData,
+        }
+    }
+}
+
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Ic,
+{
+    /// Set the device in low-power mode (shutdown).
+    pub async fn disable(&mut self) -> Result<(), Error<E>> {
+        let config = self.config.with_high(BitFlags::SHUTDOWN);
+        self.write_config(config).await
+    }
+
+    /// Enable the device (this is the default state).
+    pub async fn enable(&mut self) -> Result<(), Error<E>> {
+        let config = self.config.with_low(BitFlags::SHUTDOWN);
+        self.write_config(config).await
+    }
+
+    /// Read the temperature from the sensor.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        let data = self.read_register(Register::TEMPERATURE).await?;
+        Ok(convert_temp_from_register(
+            data[0],
+            data[1],
+            self.resolution_mask(),
+            self.extended_mode,
+        ))
+    }
+
+    /// Set the fault queue.
+    pub async fn set_fault_queue(&mut self, fq: FaultQueue) -> Result<(), Error<E>> {
+        let config = self
+            .config
+            .with_low(BitFlags::FAULT_QUEUE0 | BitFlags::FAULT_QUEUE1);
+        let config = match fq {
+            FaultQueue::_1 => config,
+            FaultQueue::_2 => config.with_high(BitFlags::FAULT_QUEUE0),
+            FaultQueue::_4 => config.with_high(BitFlags::FAULT_QUEUE1),
+            FaultQueue::_6 => config.with_high(BitFlags::FAULT_QUEUE0 | BitFlags::FAULT_QUEUE1),
+        };
+        self.write_config(config).await
+    }
+
+    /// Set the OS polarity.
+    pub async fn set_os_polarity(&mut self, polarity: OsPolarity) -> Result<(), Error<E>> {
+        let config = match polarity {
+            OsPolarity::ActiveHigh => self.config.with_high(BitFlags::OS_POLARITY),
+            OsPolarity::ActiveLow => self.config.with_low(BitFlags::OS_POLARITY),
+        };
+        self.write_config(config).await
+    }
+
+    /// Set the OS operation mode.
+    pub async fn set_os_mode(&mut self, mode: OsMode) -> Result<(), Error<E>> {
+        let config = match mode {
+            OsMode::Interrupt => self.config.with_high(BitFlags::COMP_INT),
+            OsMode::Comparator => self.config.with_low(BitFlags::COMP_INT),
+        };
+        self.write_config(config).await
+    }
+
+    /// Set the OS temperature (T_OS, overtemperature shutdown threshold).
+    pub async fn set_os_temperature(&mut self, temperature: f32) -> Result<(), Error<E>> {
+        self.write_limit_register(Register::T_OS, temperature).await
+    }
+
+    /// Set the hysteresis temperature (T_HYST).
+    pub async fn set_hysteresis_temperature(&mut self, temperature: f32) -> Result<(), Error<E>> {
+        self.write_limit_register(Register::T_HYST, temperature).await
+    }
+
+    /// Read back the configured OS temperature (T_OS, overtemperature
+    /// shutdown threshold).
+    pub async fn read_os_temperature(&mut self) -> Result<f32, Error<E>> {
+        let data = self.read_register(Register::T_OS).await?;
+        Ok(convert_temp_from_register(
+            data[0],
+            data[1],
+            self.resolution_mask(),
+            self.extended_mode,
+        ))
+    }
+
+    /// Read back the configured hysteresis temperature (T_HYST).
+    pub async fn read_hysteresis_temperature(&mut self) -> Result<f32, Error<E>> {
+        let data = self.read_register(Register::T_HYST).await?;
+        Ok(convert_temp_from_register(
+            data[0],
+            data[1],
+            self.resolution_mask(),
+            self.extended_mode,
+        ))
+    }
+
+    /// Trigger a single conversion while the device is in shutdown.
+    ///
+    /// See [`crate::Lm75::trigger_one_shot`] for details.
+    pub async fn trigger_one_shot(&mut self) -> Result<(), Error<E>> {
+        if !IC::HAS_ONE_SHOT {
+            return Err(Error::InvalidRegister);
+        }
+        let shutdown_config = self.config.with_high(BitFlags::SHUTDOWN);
+        let one_shot_config = shutdown_config.with_high(BitFlags::ONE_SHOT);
+        self.i2c
+            .write(self.address, &[Register::CONFIGURATION, one_shot_config.bits])
+            .await
+            .map_err(Error::I2C)?;
+        self.config = shutdown_config;
+        Ok(())
+    }
+
+    /// Trigger a one-shot conversion, await its completion and read the
+    /// result back.
+    pub async fn read_temperature_one_shot<D: embedded_hal_async::delay::DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<E>> {
+        self.trigger_one_shot().await?;
+        delay.delay_ms(self.conversion_time_ms()).await;
+        self.read_temperature().await
+    }
+
+    /// Set the measurement resolution.
+    ///
+    /// See [`crate::Lm75::set_resolution`] for details.
+    pub async fn set_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        if !IC::HAS_CONFIGURABLE_RESOLUTION {
+            return Err(Error::InvalidRegister);
+        }
+        let config = self
+            .config
+            .with_low(BitFlags::RESOLUTION)
+            .with_high(resolution_config_bits(resolution));
+        self.write_config(config).await?;
+        self.resolution = resolution;
+        Ok(())
+    }
+
+    /// The currently active measurement resolution.
+    pub fn resolution(&self) -> Resolution {
+        if IC::HAS_CONFIGURABLE_RESOLUTION {
+            self.resolution
+        } else {
+            Resolution::from_mask(IC::RESOLUTION_MASK)
+        }
+    }
+
+    /// Nominal conversion time in milliseconds at the currently active
+    /// resolution.
+    pub fn conversion_time_ms(&self) -> u32 {
+        if IC::HAS_CONFIGURABLE_RESOLUTION {
+            self.resolution.conversion_time_ms()
+        } else {
+            IC::CONVERSION_TIME_MS
+        }
+    }
+
+    /// Program the T_idle sample period (PCT2075-class variants only).
+    ///
+    /// See [`crate::Lm75::set_sample_period`] for details.
+    pub async fn set_sample_period(&mut self, ms: u16) -> Result<(), Error<E>> {
+        if !IC::HAS_SAMPLE_RATE {
+            return Err(Error::InvalidRegister);
+        }
+        if !(100..=3100).contains(&ms) {
+            return Err(Error::InvalidInputData);
+        }
+        let t_idle = (ms / 100) as u8;
+        self.i2c
+            .write(self.address, &[Register::IDLE, t_idle])
+            .await
+            .map_err(Error::I2C)?;
+        self.sample_rate = SampleRate { bits: Some(t_idle) };
+        Ok(())
+    }
+
+    /// The currently programmed T_idle sample period in milliseconds, or
+    /// `None` on variants without a programmable sample rate.
+    pub fn sample_period(&self) -> Option<u16> {
+        self.sample_rate.bits.map(|bits| u16::from(bits) * 100)
+    }
+
+    /// Enable extended (13-bit) measurement mode.
+    ///
+    /// See [`crate::Lm75::enable_extended_mode`] for details.
+    pub async fn enable_extended_mode(&mut self) -> Result<(), Error<E>> {
+        if !IC::HAS_EXTENDED_MODE {
+            return Err(Error::InvalidRegister);
+        }
+        let config = self.config.with_high(BitFlags::EXTENDED_MODE);
+        self.write_config(config).await?;
+        self.extended_mode = true;
+        Ok(())
+    }
+
+    /// Disable extended measurement mode, returning to the normal format.
+    pub async fn disable_extended_mode(&mut self) -> Result<(), Error<E>> {
+        if !IC::HAS_EXTENDED_MODE {
+            return Err(Error::InvalidRegister);
+        }
+        let config = self.config.with_low(BitFlags::EXTENDED_MODE);
+        self.write_config(config).await?;
+        self.extended_mode = false;
+        Ok(())
+    }
+
+    fn resolution_mask(&self) -> u8 {
+        if IC::HAS_CONFIGURABLE_RESOLUTION {
+            self.resolution as u8
+        } else {
+            IC::RESOLUTION_MASK
+        }
+    }
+
+    async fn write_config(&mut self, config: Config) -> Result<(), Error<E>> {
+        self.i2c
+            .write(self.address, &[Register::CONFIGURATION, config.bits])
+            .await
+            .map_err(Error::I2C)?;
+        self.config = config;
+        Ok(())
+    }
+
+    async fn write_limit_register(&mut self, register: u8, temperature: f32) -> Result<(), Error<E>> {
+        let (msb, lsb) = convert_temp_to_register(temperature, self.resolution_mask(), self.extended_mode);
+        self.i2c
+            .write(self.address, &[register, msb, lsb])
+            .await
+            .map_err(Error::I2C)
+    }
+
+    async fn read_register(&mut self, register: u8) -> Result<[u8; 2], Error<E>> {
+        let mut data = [0; 2];
+        self.i2c
+            .write_read(self.address, &[register], &mut data)
+            .await
+            .map_err(Error::I2C)?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    const ADDR: u8 = crate::DEVICE_BASE_ADDRESS;
+
+    #[tokio::test]
+    async fn reads_temperature_at_default_resolution() {
+        let expectations = [I2cTransaction::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0x19, 0x00],
+        )];
+        let mut sensor = Lm75::new(I2cMock::new(&expectations), Address::default());
+        assert_eq!(sensor.read_temperature().await.unwrap(), 25.0);
+        sensor.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn one_shot_sets_shutdown_and_one_shot_bits() {
+        let config = BitFlags::SHUTDOWN | BitFlags::ONE_SHOT;
+        let expectations = [I2cTransaction::write(
+            ADDR,
+            vec![Register::CONFIGURATION, config],
+        )];
+        let mut sensor = Lm75::new_g751(I2cMock::new(&expectations), Address::default());
+        sensor.trigger_one_shot().await.unwrap();
+        sensor.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn extended_mode_round_trips_through_the_device() {
+        let expectations = [
+            I2cTransaction::write(ADDR, vec![Register::CONFIGURATION, BitFlags::EXTENDED_MODE]),
+            I2cTransaction::write_read(ADDR, vec![Register::TEMPERATURE], vec![0xF6, 0x88]),
+        ];
+        let mut sensor = Lm75::new_tmp102(I2cMock::new(&expectations), Address::default());
+        sensor.enable_extended_mode().await.unwrap();
+        assert_eq!(sensor.read_temperature().await.unwrap(), -9.5);
+        sensor.i2c.done();
+    }
+}
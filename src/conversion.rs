@@ -0,0 +1,143 @@
+//! Register addresses and the raw two's-complement conversions shared by
+//! every chip variant.
+
+use crate::Resolution;
+
+/// Register addresses.
+pub(crate) struct Register;
+
+impl Register {
+    pub(crate) const TEMPERATURE: u8 = 0x00;
+    pub(crate) const CONFIGURATION: u8 = 0x01;
+    pub(crate) const T_HYST: u8 = 0x02;
+    pub(crate) const T_OS: u8 = 0x03;
+    pub(crate) const IDLE: u8 = 0x04;
+}
+
+/// Configuration register bit flags.
+pub(crate) struct BitFlags;
+
+impl BitFlags {
+    pub(crate) const SHUTDOWN: u8 = 0b0000_0001;
+    pub(crate) const COMP_INT: u8 = 0b0000_0010;
+    pub(crate) const OS_POLARITY: u8 = 0b0000_0100;
+    pub(crate) const FAULT_QUEUE0: u8 = 0b0000_1000;
+    pub(crate) const FAULT_QUEUE1: u8 = 0b0001_0000;
+    pub(crate) const RESOLUTION: u8 = 0b0110_0000;
+    pub(crate) const ONE_SHOT: u8 = 0b1000_0000;
+    /// Extended (13-bit) measurement mode. Only ever written on variants
+    /// with [`crate::ic::Ic::HAS_EXTENDED_MODE`] set; `Ic::ASSERT_RESOLUTION_EXTENDED_MODE_DISJOINT`
+    /// enforces at compile time that no such variant also has
+    /// `HAS_CONFIGURABLE_RESOLUTION`, so this intentionally reuses the R0
+    /// bit's position without ever colliding with `RESOLUTION` in practice.
+    pub(crate) const EXTENDED_MODE: u8 = 0b0010_0000;
+}
+
+/// Encode a [`Resolution`] as the R1:R0 bits (6:5) of the configuration
+/// register.
+pub(crate) fn resolution_config_bits(resolution: Resolution) -> u8 {
+    match resolution {
+        Resolution::Bits9 => 0b0000_0000,
+        Resolution::Bits10 => 0b0010_0000,
+        Resolution::Bits11 => 0b0100_0000,
+        Resolution::Bits12 => 0b0110_0000,
+    }
+}
+
+/// Decode a two's-complement temperature-format register pair (MSB, LSB)
+/// into °C, given the mask of fractional bits carried in the LSB.
+///
+/// This is used for the temperature register itself as well as for the
+/// T_OS and T_HYST limit registers, which share the same format. In
+/// normal mode MSB is a signed 8-bit integer part. `extended` does not
+/// touch the fractional bits at all (so a byte pair that falls inside the
+/// normal range decodes to the exact same value in either mode); instead
+/// it reclaims the otherwise-unused LSB bit directly below the fraction
+/// mask as one extra, more-significant integer bit, with MSB itself
+/// reinterpreted as unsigned magnitude. That bit becomes the new sign,
+/// stretching the representable range roughly symmetrically past ±128 °C.
+pub(crate) fn convert_temp_from_register(msb: u8, lsb: u8, mask: u8, extended: bool) -> f32 {
+    let frac_bits = mask.count_ones();
+    let frac = i16::from((lsb & mask) >> (8 - frac_bits));
+    let whole = if extended {
+        let extra_bit = i16::from((lsb >> (7 - frac_bits)) & 0x01);
+        let raw = (extra_bit << 8) | i16::from(msb);
+        if raw >= 256 {
+            raw - 512
+        } else {
+            raw
+        }
+    } else {
+        i16::from(msb as i8)
+    };
+    let combined = (whole << frac_bits) | frac;
+    combined as f32 / (1_i32 << frac_bits) as f32
+}
+
+/// Encode a temperature in °C into a two's-complement register pair
+/// (MSB, LSB) at the given fractional-bit mask, the inverse of
+/// [`convert_temp_from_register`].
+pub(crate) fn convert_temp_to_register(temp_c: f32, mask: u8, extended: bool) -> (u8, u8) {
+    let frac_bits = mask.count_ones();
+    let scale = (1_i32 << frac_bits) as f32;
+    let combined = round_to_i32(temp_c * scale);
+    let frac = (combined & ((1 << frac_bits) - 1)) as u8;
+    if extended {
+        let raw = (combined >> frac_bits) & 0x1FF;
+        let msb = (raw & 0xFF) as u8;
+        let extra_bit = ((raw >> 8) & 0x01) as u8;
+        let lsb = (extra_bit << (7 - frac_bits)) | (frac << (8 - frac_bits));
+        (msb, lsb)
+    } else {
+        let msb = (combined >> frac_bits) as u8;
+        let lsb = frac << (8 - frac_bits);
+        (msb, lsb)
+    }
+}
+
+/// Round-half-away-from-zero, since `f32::round` needs `std` and this
+/// crate is `no_std`.
+fn round_to_i32(x: f32) -> i32 {
+    if x >= 0.0 {
+        (x + 0.5) as i32
+    } else {
+        (x - 0.5) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const G751_MASK: u8 = 0b1000_0000;
+
+    #[test]
+    fn extended_and_normal_agree_on_overlapping_range() {
+        for &temp in &[-50.0_f32, -9.5, 0.0, 0.5, 25.5, 100.0, 120.5] {
+            let (msb, lsb) = convert_temp_to_register(temp, G751_MASK, false);
+            assert_eq!(convert_temp_from_register(msb, lsb, G751_MASK, false), temp);
+
+            let (msb, lsb) = convert_temp_to_register(temp, G751_MASK, true);
+            assert_eq!(convert_temp_from_register(msb, lsb, G751_MASK, true), temp);
+        }
+    }
+
+    #[test]
+    fn extended_mode_reaches_beyond_normal_range() {
+        let (msb, lsb) = convert_temp_to_register(150.0, G751_MASK, true);
+        assert_eq!(convert_temp_from_register(msb, lsb, G751_MASK, true), 150.0);
+
+        let (msb, lsb) = convert_temp_to_register(-150.0, G751_MASK, true);
+        assert_eq!(convert_temp_from_register(msb, lsb, G751_MASK, true), -150.0);
+    }
+
+    #[test]
+    fn resolution_round_trip_at_every_mask() {
+        for &mask in &[0b1000_0000u8, 0b1100_0000, 0b1110_0000, 0b1111_0000] {
+            for &temp in &[-40.0_f32, -0.5, 0.0, 23.0, 99.5] {
+                let (msb, lsb) = convert_temp_to_register(temp, mask, false);
+                assert_eq!(convert_temp_from_register(msb, lsb, mask, false), temp);
+            }
+        }
+    }
+}
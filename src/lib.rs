@@ -8,9 +8,19 @@
 //! - Read the temperature.
 //! - Set the fault queue.
 //! - Set the OS temperature.
+//! - Read back the OS temperature.
 //! - Set the hysteresis temperature.
+//! - Read back the hysteresis temperature.
 //! - Set the OS operation mode.
 //! - Set the OS polarity.
+//! - Trigger a one-shot conversion on shutdown-capable variants.
+//! - Set the measurement resolution on variants that support it.
+//! - Program the T_idle sample period on PCT2075-class variants.
+//! - Enable extended (13-bit) measurement mode on variants that support it
+//!   (currently only [`ic::Tmp102`]).
+//!
+//! An async mirror of the whole API is available behind the `async`
+//! feature; see the [`asynch`] module.
 //!
 //! ## The device
 //!
@@ -35,7 +45,7 @@
 //!
 //! This driver is also compatible with at least [LM75A], [LM75B, LM75C],
 //! [AT30TS75A], [DS1775], [DS75], [DS7505], [G751], [MAX7500/1/2/3/4],
-//! [MAX6625], [MCP9800/1/2/3], [STDS75], [TCN75].
+//! [MAX6625], [MCP9800/1/2/3], [STDS75], [TCN75], [TMP102/TMP112].
 //!
 //! [AT30TS75A]: http://ww1.microchip.com/downloads/en/DeviceDoc/Atmel-8839-DTS-AT30TS75A-Datasheet.pdf
 //! [DS1775]: https://datasheets.maximintegrated.com/en/ds/DS1775-DS1775R.pdf
@@ -49,6 +59,7 @@
 //! [MCP9800/1/2/3]: http://ww1.microchip.com/downloads/en/DeviceDoc/21909d.pdf
 //! [STDS75]: https://www.st.com/resource/en/datasheet/stds75.pdf
 //! [TCN75]: http://ww1.microchip.com/downloads/en/DeviceDoc/21490D.pdf
+//! [TMP102/TMP112]: https://www.ti.com/lit/ds/symlink/tmp102.pdf
 //!
 //! ## Usage examples (see also examples folder)
 //!
@@ -80,7 +91,7 @@
 //!
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let (a2, a1, a0) = (false, false, true);
-//! let address = SlaveAddr::Alternative(a2, a1, a0);
+//! let address = SlaveAddr::from((a2, a1, a0));
 //! let mut sensor = Lm75::new(dev, address);
 //! ```
 //!
@@ -144,6 +155,83 @@
 //! sensor.set_hysteresis_temperature(temp_celsius).unwrap();
 //! ```
 //!
+//! ### Use a specific chip variant
+//!
+//! Pin- and protocol-compatible parts differ in resolution and supported
+//! features; pick the matching constructor to get the right behavior.
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_ds7505(dev, SlaveAddr::default());
+//! let temp_celsius = sensor.read_temperature().unwrap();
+//! println!("Temperature: {}ºC", temp_celsius);
+//! ```
+//!
+//! ### Read back the OS and hysteresis temperatures
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new(dev, SlaveAddr::default());
+//! let os_celsius = sensor.read_os_temperature().unwrap();
+//! let hyst_celsius = sensor.read_hysteresis_temperature().unwrap();
+//! println!("T_OS: {}ºC, T_HYST: {}ºC", os_celsius, hyst_celsius);
+//! ```
+//!
+//! ### Set the measurement resolution
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Resolution, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_ds7505(dev, SlaveAddr::default());
+//! sensor.set_resolution(Resolution::Bits12).unwrap();
+//! ```
+//!
+//! ### Program the sample period (PCT2075)
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_pct2075(dev, SlaveAddr::default());
+//! sensor.set_sample_period(500).unwrap();
+//! ```
+//!
+//! ### Extended measurement mode
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_tmp102(dev, SlaveAddr::default());
+//! sensor.enable_extended_mode().unwrap();
+//! let temp_celsius = sensor.read_temperature().unwrap(); // can now exceed +128ºC
+//! println!("Temperature: {}ºC", temp_celsius);
+//! ```
+//!
+//! ### One-shot conversion while shut down
+//!
+//! ```no_run
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use lm75::{Lm75, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_ds7505(dev, SlaveAddr::default());
+//! let mut delay = Delay;
+//! sensor.disable().unwrap();
+//! let temp_celsius = sensor.read_temperature_one_shot(&mut delay).unwrap();
+//! println!("Temperature: {}ºC", temp_celsius);
+//! ```
+//!
 //! ### Enable / disable the sensor
 //!
 //! ```no_run
@@ -159,8 +247,10 @@
 #![deny(missing_docs, unsafe_code)]
 #![no_std]
 
+use core::marker::PhantomData;
+
 /// All possible errors in this crate
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Error<E> {
     /// I²C bus error
     I2C(E),
@@ -244,23 +334,226 @@ pub enum OsMode {
     Interrupt,
 }
 
-#[derive(Debug, Clone, Copy)]
-/// Device Resolution
+/// Device measurement resolution.
+///
+/// Masks the fractional bits carried in the LSB of the temperature, T_OS
+/// and T_HYST registers. On variants where [`ic::Ic::HAS_CONFIGURABLE_RESOLUTION`]
+/// is `true` this can be written to the device with
+/// [`Lm75::set_resolution`]; on fixed-resolution variants it is derived
+/// from the chip's marker type and cannot be changed.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum Resolution {
-    /// 9bit has 0.5 resolution, 11bit has 0.125
-    /// Masks the LSB only
-    Mask9bit = 0b1000_0000,
-    /// Sensors with 11-bit resolution (PCT2075)
-    Mask11bit = 0b1110_0000,
+    /// 9-bit, 0.5 °C per LSB (the default on power-up).
+    #[default]
+    Bits9 = 0b1000_0000,
+    /// 10-bit, 0.25 °C per LSB.
+    Bits10 = 0b1100_0000,
+    /// 11-bit, 0.125 °C per LSB (e.g. PCT2075's fixed resolution).
+    Bits11 = 0b1110_0000,
+    /// 12-bit, 0.0625 °C per LSB.
+    Bits12 = 0b1111_0000,
+}
+
+impl Resolution {
+    /// Nominal conversion time in milliseconds at this resolution.
+    pub(crate) fn conversion_time_ms(self) -> u32 {
+        match self {
+            Resolution::Bits9 => 25,
+            Resolution::Bits10 => 50,
+            Resolution::Bits11 => 100,
+            Resolution::Bits12 => 200,
+        }
+    }
+
+    /// Reconstruct a `Resolution` from a raw fractional-bit mask, as
+    /// carried by [`ic::Ic::RESOLUTION_MASK`]. The sole source of truth for
+    /// a fixed-resolution variant's reported resolution, so it can never
+    /// drift out of step with the mask the conversion code actually uses.
+    pub(crate) fn from_mask(mask: u8) -> Resolution {
+        match mask {
+            0b1100_0000 => Resolution::Bits10,
+            0b1110_0000 => Resolution::Bits11,
+            0b1111_0000 => Resolution::Bits12,
+            _ => Resolution::Bits9,
+        }
+    }
+}
+
+/// Marker types identifying the concrete LM75-family chip in use.
+///
+/// The LM75 register map is shared by a long list of pin- and
+/// protocol-compatible parts (see the crate docs), but they differ in
+/// resolution, register width and supported features. Rather than track
+/// this with a runtime enum, [`Lm75`](crate::Lm75) carries the variant as a
+/// zero-sized type parameter so the conversion code can consult the
+/// variant's constants at compile time instead of branching on it.
+pub mod ic {
+    /// Sealed trait supplying the per-chip constants used to interpret and
+    /// encode a variant's registers.
+    ///
+    /// This trait cannot be implemented outside of this crate; use one of
+    /// the marker types in this module instead.
+    pub trait Ic: crate::private::Sealed {
+        /// Mask of the fractional bits in the LSB of the temperature,
+        /// T_OS and T_HYST registers at this variant's fixed resolution.
+        /// On variants with `HAS_CONFIGURABLE_RESOLUTION` set this is the
+        /// widest (12-bit) mask and is not used; the active
+        /// [`crate::Resolution`] supplies the mask instead.
+        const RESOLUTION_MASK: u8;
+        /// Whether the configuration register's R1:R0 resolution bits
+        /// (9-to-12-bit) are implemented on this variant.
+        const HAS_CONFIGURABLE_RESOLUTION: bool;
+        /// The resolution this variant's R1:R0 bits power up in, before any
+        /// [`Lm75::set_resolution`](crate::Lm75::set_resolution) call.
+        /// Ignored on variants with `HAS_CONFIGURABLE_RESOLUTION` unset,
+        /// where [`crate::ic::Ic::RESOLUTION_MASK`] is used instead.
+        const DEFAULT_RESOLUTION: crate::Resolution;
+        /// Whether the T_idle programmable sample-rate register (0x04)
+        /// is implemented on this variant.
+        const HAS_SAMPLE_RATE: bool;
+        /// Whether the one-shot config bit (bit 7) is implemented, letting
+        /// a shut-down device perform a single conversion on demand.
+        const HAS_ONE_SHOT: bool;
+        /// Whether the extended (13-bit) measurement mode is implemented,
+        /// using one spare LSB bit as an extra integer bit for headroom
+        /// above +128 °C; the fractional precision is unchanged.
+        const HAS_EXTENDED_MODE: bool;
+        /// Nominal conversion time in milliseconds on fixed-resolution
+        /// variants. On variants with `HAS_CONFIGURABLE_RESOLUTION` set
+        /// this is not used; the conversion time of the active
+        /// [`crate::Resolution`] is used instead.
+        const CONVERSION_TIME_MS: u32;
+
+        /// Compile-time proof that this variant doesn't set both
+        /// `HAS_CONFIGURABLE_RESOLUTION` and `HAS_EXTENDED_MODE`: the
+        /// extended-mode configuration bit reuses the position of the R0
+        /// resolution bit, so on a variant with both, writing one setting
+        /// would overwrite the other. Referenced from every `new_variant`
+        /// so a violating `impl Ic` fails to compile wherever it's used,
+        /// not just where it's defined.
+        const ASSERT_RESOLUTION_EXTENDED_MODE_DISJOINT: () =
+            assert!(!(Self::HAS_CONFIGURABLE_RESOLUTION && Self::HAS_EXTENDED_MODE));
+    }
+
+    /// Plain LM75/LM75A and the many 9-bit-only pin-compatible parts
+    /// (LM75B/C, AT30TS75A, DS75, MAX6625, MAX7500-4, MCP980x, STDS75,
+    /// TCN75, ...). This is the default variant used by [`Lm75::new`](crate::Lm75::new).
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Lm75;
+
+    /// Maxim/Dallas DS7505: configurable 9-to-12-bit resolution.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Ds7505;
+
+    /// Maxim DS1775: configurable 9-to-12-bit resolution.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Ds1775;
+
+    /// TI TMP175/TMP275: configurable 9-to-12-bit resolution.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Tmp175;
+
+    /// GMT G751: 9-bit resolution, one-shot capable.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct G751;
+
+    /// NXP PCT2075: 11-bit resolution with a programmable T_idle sample rate.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Pct2075;
+
+    /// TI TMP102/TMP112: fixed 12-bit resolution, with a genuine extended
+    /// (13-bit) measurement mode (EM bit) for readings above +128 °C.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Tmp102;
+
+    impl Ic for Lm75 {
+        const RESOLUTION_MASK: u8 = 0b1000_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = false;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits9;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = false;
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 100;
+    }
+
+    impl Ic for Ds7505 {
+        const RESOLUTION_MASK: u8 = 0b1111_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = true;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits9;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = true;
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 200;
+    }
+
+    impl Ic for Ds1775 {
+        const RESOLUTION_MASK: u8 = 0b1111_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = true;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits9;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = true;
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 200;
+    }
+
+    impl Ic for Tmp175 {
+        const RESOLUTION_MASK: u8 = 0b1111_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = true;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits12;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = true;
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 220;
+    }
+
+    impl Ic for G751 {
+        const RESOLUTION_MASK: u8 = 0b1000_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = false;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits9;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = true;
+        // Bit 5 of the configuration register is reserved on real G751
+        // silicon, not an EM (extended-mode) bit as on the TMP102/TMP112;
+        // see `Tmp102` for the variant that actually implements it.
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 150;
+    }
+
+    impl Ic for Pct2075 {
+        const RESOLUTION_MASK: u8 = 0b1110_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = false;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits9;
+        const HAS_SAMPLE_RATE: bool = true;
+        const HAS_ONE_SHOT: bool = false;
+        const HAS_EXTENDED_MODE: bool = false;
+        const CONVERSION_TIME_MS: u32 = 100;
+    }
+
+    impl Ic for Tmp102 {
+        const RESOLUTION_MASK: u8 = 0b1111_0000;
+        const HAS_CONFIGURABLE_RESOLUTION: bool = false;
+        const DEFAULT_RESOLUTION: crate::Resolution = crate::Resolution::Bits12;
+        const HAS_SAMPLE_RATE: bool = false;
+        const HAS_ONE_SHOT: bool = false;
+        const HAS_EXTENDED_MODE: bool = true;
+        const CONVERSION_TIME_MS: u32 = 26;
+    }
 }
 
-impl Default for Resolution {
-    fn default() -> Self { Resolution::Mask9bit }
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::ic::Lm75 {}
+    impl Sealed for crate::ic::Ds7505 {}
+    impl Sealed for crate::ic::Ds1775 {}
+    impl Sealed for crate::ic::Tmp175 {}
+    impl Sealed for crate::ic::G751 {}
+    impl Sealed for crate::ic::Pct2075 {}
+    impl Sealed for crate::ic::Tmp102 {}
 }
 
 const DEVICE_BASE_ADDRESS: u8 = 0b100_1000;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 struct Config {
     bits: u8,
 }
@@ -278,12 +571,6 @@ impl Config {
     }
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Config { bits: 0 }
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 struct SampleRate {
     bits: Option<u8>,
@@ -298,8 +585,13 @@ impl SampleRate {
 }
 
 /// LM75 device driver.
+///
+/// `IC` identifies the concrete chip variant (see the [`ic`] module) and
+/// defaults to a plain [`ic::Lm75`]. It carries no runtime state; it only
+/// selects which constants the conversion code uses, so there is no
+/// overhead compared to targeting a single fixed chip.
 #[derive(Debug, Default)]
-pub struct Lm75<I2C> {
+pub struct Lm75<I2C, IC = ic::Lm75> {
     /// The concrete I²C device implementation.
     i2c: I2C,
     /// The I²C device address.
@@ -310,10 +602,17 @@ pub struct Lm75<I2C> {
     resolution: Resolution,
     /// T-Idle Register Contents
     sample_rate: SampleRate,
+    /// Whether extended (13-bit) measurement mode is active.
+    extended_mode: bool,
+    /// Zero-sized marker for the chip variant `IC`.
+    _ic: PhantomData<IC>,
 }
 
+#[cfg(feature = "async")]
+pub mod asynch;
 mod conversion;
 mod device_impl;
+pub use device_impl::SlaveAddr;
 
 #[cfg(test)]
 mod tests {